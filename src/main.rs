@@ -1,63 +1,190 @@
+// `return Ok(...)` as the last statement of a function and `while let Some(x)
+// = iter.next()` are used throughout this file; that predates clippy being
+// run against this crate and isn't worth rewriting wholesale.
+#![allow(clippy::needless_return, clippy::while_let_on_iterator)]
+
 use std::process::ExitCode;
 use std::path::{Path, PathBuf};
 use std::io;
 
-// Bytes Units
+use regex::Regex;
+use rayon::prelude::*;
+use terminal_size::{Width, terminal_size};
+
+// Bytes Units (decimal, SI)
 const KILOBYTE: f64 = 1_000.0;
 /// 1 MB = 10<sup>6</sup> bytes.
 const MEGABYTE: f64 = 1_000_000.0;
 /// 1 GB = 10<sup>9</sup> bytes.
 const GIGABYTE: f64 = 1_000_000_000.0;
 /// 1 TB = 10<sup>12</sup> bytes.
-const TERABYTE: f64 = 1_000_000_000_0.0;
+const TERABYTE: f64 = 1_000_000_000_000.0;
+
+// Bytes Units (binary, IEC)
+const KIBIBYTE: f64 = 1_024.0;
+/// 1 MiB = 2<sup>20</sup> bytes.
+const MEBIBYTE: f64 = 1_024.0 * 1_024.0;
+/// 1 GiB = 2<sup>30</sup> bytes.
+const GIBIBYTE: f64 = 1_024.0 * 1_024.0 * 1_024.0;
+/// 1 TiB = 2<sup>40</sup> bytes.
+const TEBIBYTE: f64 = 1_024.0 * 1_024.0 * 1_024.0 * 1_024.0;
+
+/// Which style `pretty_format_bytes` renders a size in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum UnitMode {
+    /// 1000-based KB/MB/GB/TB, the historical default.
+    Decimal,
+    /// 1024-based KiB/MiB/GiB/TiB.
+    Iec,
+    /// Exact byte count with thousands separators, for scripting.
+    Bytes,
+}
 
 fn usage(program: &String) {
     println!("Usage:");
     println!("  {program} [OPTION] [DIR]");
     println!("    -a         Option to display entries that start with `.`");
     println!("    -i         Option to display only the information about a directory instead of its contents");
+    println!("    -d, --depth N   Recurse into subdirectories up to N levels deep (default 1)");
+    println!("    -u, --usage     Report actual on-disk usage (blocks) instead of apparent size");
+    println!("    -x, --exclude PATTERN   Skip files/dirs whose name matches PATTERN (glob, repeatable)");
+    println!("    -b, --bytes     Print exact byte counts with thousands separators instead of KB/MB/...");
+    println!("    --iec           Use 1024-based KiB/MiB/GiB/TiB units instead of 1000-based KB/MB/GB/TB");
+    println!("    -j, --jobs N    Number of worker threads to use when walking large directories (default: available parallelism)");
+    println!("    -A, --ascii     Disable ANSI colors and draw usage bars with plain ASCII");
+    println!("    --sort=name|size|mtime   Sort listings by name, size (default), or modification time");
+    println!("    --reverse       Reverse the sort order");
+    println!("    --dirs-only     List only directories");
+    println!("    --files-only    List only files");
     println!("    DIR        Provide directory to list contents of");
     println!("    --help     Display this help message");
 }
 
-fn get_size<P: AsRef<Path>>(path: P) -> io::Result<u64> {
-    let mut result = 0;
-    if path.as_ref().is_dir() {
-	for entry in std::fs::read_dir(&path)? {
-	    let entry_path = entry?.path();
-	    if entry_path.is_dir() {
-		result += get_size(entry_path)?;
-	    } else {
-		result += entry_path.metadata()?.len();
+/// Size of a single entry given its metadata. With `use_disk_usage`, reports
+/// actual on-disk allocation (`blocks() * 512`) instead of the logical/apparent
+/// length, matching what `du` shows. Windows has no block-count metadata, so
+/// it always falls back to the logical length.
+#[cfg(unix)]
+fn entry_size(metadata: &std::fs::Metadata, use_disk_usage: bool) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    if use_disk_usage {
+	metadata.blocks() * 512
+    } else {
+	metadata.len()
+    }
+}
+
+#[cfg(not(unix))]
+fn entry_size(metadata: &std::fs::Metadata, _use_disk_usage: bool) -> u64 {
+    metadata.len()
+}
+
+/// Translates a dutree-style glob (`*` and `?` wildcards) into a `Regex`
+/// anchored against the whole file name, escaping everything else so literal
+/// regex metacharacters in a pattern like `foo.tmp` aren't misinterpreted.
+fn glob_to_regex(pattern: &str) -> Result<Regex, String> {
+    const REGEX_META: &str = ".^$+()[]{}|\\";
+    let mut translated = String::from("^");
+    for ch in pattern.chars() {
+	match ch {
+	    '*' => translated.push_str(".*"),
+	    '?' => translated.push('.'),
+	    c => {
+		if REGEX_META.contains(c) {
+		    translated.push('\\');
+		}
+		translated.push(c);
 	    }
 	}
+    }
+    translated.push('$');
+    Regex::new(&translated).map_err(|e| format!("Invalid exclude pattern `{pattern}`: {e}"))
+}
+
+fn is_excluded(file_name: &str, excludes: &[Regex]) -> bool {
+    excludes.iter().any(|re| re.is_match(file_name))
+}
+
+/// Size of a single `read_dir` entry. Uses `DirEntry::file_type`/`metadata`
+/// (lstat-based, unlike `Path::is_dir`/`Path::metadata`) so a symlink is
+/// always sized as itself rather than followed — that's what keeps a symlink
+/// cycle from recursing forever.
+fn size_of_entry(entry: &std::fs::DirEntry, use_disk_usage: bool, excludes: &[Regex], pool: &rayon::ThreadPool) -> io::Result<u64> {
+    if entry.file_type()?.is_dir() {
+	get_size(entry.path(), use_disk_usage, excludes, pool)
     } else {
-	result = path.as_ref().metadata()?.len();
+	Ok(entry_size(&entry.metadata()?, use_disk_usage))
     }
-    
-    return Ok(result);
 }
 
-fn pretty_format_bytes(bytes: u64) -> String {
+/// Directories with fewer entries than this are summed on the calling thread;
+/// below this the cost of fanning out across the pool outweighs the win.
+const PARALLEL_WALK_THRESHOLD: usize = 32;
+
+fn get_size<P: AsRef<Path>>(path: P, use_disk_usage: bool, excludes: &[Regex], pool: &rayon::ThreadPool) -> io::Result<u64> {
+    let path = path.as_ref();
+    if !path.is_dir() {
+	return Ok(entry_size(&path.metadata()?, use_disk_usage));
+    }
+
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(path)? {
+	let entry = entry?;
+	if is_excluded(&entry.file_name().to_string_lossy(), excludes) {
+	    continue;
+	}
+	entries.push(entry);
+    }
+
+    if entries.len() < PARALLEL_WALK_THRESHOLD {
+	let mut total = 0;
+	for entry in &entries {
+	    total += size_of_entry(entry, use_disk_usage, excludes, pool)?;
+	}
+	return Ok(total);
+    }
+
+    pool.install(|| {
+	entries
+	    .par_iter()
+	    .map(|entry| size_of_entry(entry, use_disk_usage, excludes, pool))
+	    .try_reduce(|| 0, |a, b| Ok(a + b))
+    })
+}
+
+/// Groups a non-negative integer's digits with `,` every three places, e.g.
+/// `1234567` -> `1,234,567`.
+fn group_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, ch) in digits.chars().rev().enumerate() {
+	if i > 0 && i % 3 == 0 {
+	    grouped.push(',');
+	}
+	grouped.push(ch);
+    }
+    grouped.chars().rev().collect()
+}
+
+fn pretty_format_bytes(bytes: u64, mode: UnitMode) -> String {
+    if mode == UnitMode::Bytes {
+	return format!("{}B", group_thousands(bytes));
+    }
     let fbytes = bytes as f64;
-    if fbytes >= TERABYTE {
-	let tb = fbytes / TERABYTE;
-	format!("{:.3}TB", tb)
-    } else if fbytes >= GIGABYTE {
-	let gb = fbytes / GIGABYTE;
-	format!("{:.3}GB", gb)
-    } else if fbytes >= MEGABYTE {
-	let mb = fbytes / MEGABYTE;
-	format!("{:.3}MB", mb)
-    } else if fbytes >= KILOBYTE {
-	let kb = fbytes / KILOBYTE;
-	format!("{:.3}KB", kb)
+    let units: &[(f64, &str)] = if mode == UnitMode::Iec {
+	&[(TEBIBYTE, "TiB"), (GIBIBYTE, "GiB"), (MEBIBYTE, "MiB"), (KIBIBYTE, "KiB")]
     } else {
-	format!("{bytes}B")
+	&[(TERABYTE, "TB"), (GIGABYTE, "GB"), (MEGABYTE, "MB"), (KILOBYTE, "KB")]
+    };
+    for (threshold, suffix) in units {
+	if fbytes >= *threshold {
+	    return format!("{:.3}{}", fbytes / threshold, suffix);
+	}
     }
+    format!("{bytes}B")
 }
 
-fn display_info(path: &PathBuf, spacing: &'static str) -> io::Result<()> {
+fn display_info(path: &Path, spacing: &'static str, use_disk_usage: bool, excludes: &[Regex], unit_mode: UnitMode, pool: &rayon::ThreadPool) -> io::Result<()> {
     let file_type = if path.is_dir() {
 	"Dir"
     } else if path.is_file() {
@@ -77,35 +204,208 @@ fn display_info(path: &PathBuf, spacing: &'static str) -> io::Result<()> {
 	    print!("??? - ");
 	}
     }
-    let file_size = pretty_format_bytes(get_size(path)?);
+    let file_size = pretty_format_bytes(get_size(path, use_disk_usage, excludes, pool)?, unit_mode);
     let metadata = path.symlink_metadata()?;
     let read_only = metadata.permissions().readonly();
     println!("Size: {file_size} - ReadOnly: {read_only}");
     Ok(())
 }
 
-fn display_dir(dir: &PathBuf, show_dots: bool, spacing: &'static str) -> io::Result<()> {
-    let mut folders = Vec::new();
-    let mut files = Vec::new();
-    for entry in dir.read_dir()? {
-	if let Ok(entry) = entry {
-	    let file_name = entry.file_name().to_string_lossy().to_string();
-	    if !show_dots && file_name.starts_with(".") {
-		continue;
-	    }
-	    let file_type = entry.file_type()?;
-	    if file_type.is_dir() {
-		folders.push(entry.path());
-	    } else {
-		files.push(entry.path());
-	    }
+/// Columns reserved for the name before the size/bar, so bars line up across
+/// sibling entries whose names fit in it. Longer names are not truncated and
+/// push their own size and bar further right.
+const NAME_COLUMN_WIDTH: usize = 32;
+/// Columns reserved for the formatted size before the bar.
+const SIZE_COLUMN_WIDTH: usize = 12;
+
+/// Width of the attached terminal in columns, queried once at startup;
+/// falls back to 80 when it can't be determined (piped output, no TTY).
+fn terminal_width() -> usize {
+    terminal_size().map(|(Width(w), _)| w as usize).unwrap_or(80)
+}
+
+/// A proportional usage bar for `fraction` (0.0-1.0) of `width` columns,
+/// enclosed in `[`/`]` (so `width + 2` columns in all).
+/// Returns an empty string if there's no room left in the column budget.
+fn render_bar(fraction: f64, width: usize, ascii: bool) -> String {
+    if width == 0 {
+	return String::new();
+    }
+    let filled = ((fraction * width as f64).round() as usize).min(width);
+    let empty = width - filled;
+    if ascii {
+	format!("[{}{}]", "#".repeat(filled), "-".repeat(empty))
+    } else {
+	format!("[\x1b[32m{}\x1b[0m{}]", "#".repeat(filled), "-".repeat(empty))
+    }
+}
+
+/// What order `display_dir` lists sibling entries in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortKey {
+    /// Alphabetical by file name.
+    Name,
+    /// Largest first, reusing the size already computed for the usage bar.
+    Size,
+    /// Newest first.
+    Mtime,
+}
+
+/// Which kinds of entries `display_dir` lists.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EntryFilter {
+    All,
+    DirsOnly,
+    FilesOnly,
+}
+
+fn entry_passes_filter(filter: EntryFilter, is_dir: bool) -> bool {
+    match filter {
+	EntryFilter::DirsOnly => is_dir,
+	EntryFilter::FilesOnly => !is_dir,
+	EntryFilter::All => true,
+    }
+}
+
+/// Whether `dir` (already known not to pass the filter itself) has any
+/// descendant within `max_depth` that would actually print a line — used so
+/// a filtered-out parent's position in the tree doesn't dangle a connector
+/// or misjudge its siblings' `is_last` status.
+fn subtree_has_visible_entry(dir: &Path, depth: u32, max_depth: u32, opts: &ListOptions) -> bool {
+    let Ok(read_dir) = dir.read_dir() else { return false; };
+    for entry in read_dir.flatten() {
+	let file_name = entry.file_name().to_string_lossy().to_string();
+	if !opts.show_dots && file_name.starts_with(".") {
+	    continue;
+	}
+	if is_excluded(&file_name, opts.excludes) {
+	    continue;
+	}
+	let Ok(is_dir) = entry.file_type().map(|t| t.is_dir()) else { continue; };
+	if entry_passes_filter(opts.filter, is_dir) {
+	    return true;
+	}
+	if is_dir && depth < max_depth && subtree_has_visible_entry(&entry.path(), depth + 1, max_depth, opts) {
+	    return true;
 	}
     }
-    for f in folders {
-	println!("{spacing}\x1b[36m{}\x1b[0m", f.display());
+    false
+}
+
+/// Bundles the options that stay the same across an entire `display_info`/
+/// `display_dir` call tree, so recursive calls thread one reference instead
+/// of a dozen individual parameters.
+struct ListOptions<'a> {
+    show_dots: bool,
+    use_disk_usage: bool,
+    excludes: &'a [Regex],
+    unit_mode: UnitMode,
+    pool: &'a rayon::ThreadPool,
+    term_width: usize,
+    ascii: bool,
+    sort_key: SortKey,
+    reverse: bool,
+    filter: EntryFilter,
+}
+
+/// Renders `dir`'s contents as a tree, recursing into subdirectories up to
+/// `max_depth` levels. `prefix` is the already-built indentation/branch
+/// string for this level; children compute their own by extending it. Each
+/// entry gets a usage bar sized relative to the largest sibling at that level.
+/// `opts.filter` only hides entries from the printed listing — every
+/// directory is still recursed into so descendants past a filtered-out
+/// parent can still surface.
+fn display_dir(dir: &Path, prefix: &str, depth: u32, max_depth: u32, opts: &ListOptions) -> io::Result<()> {
+    let mut entries = Vec::new();
+    for entry in dir.read_dir()?.flatten() {
+	let file_name = entry.file_name().to_string_lossy().to_string();
+	if !opts.show_dots && file_name.starts_with(".") {
+	    continue;
+	}
+	if is_excluded(&file_name, opts.excludes) {
+	    continue;
+	}
+	let is_dir = entry.file_type()?.is_dir();
+	let path = entry.path();
+	// Computed once here and reused both for sorting and for the bar below.
+	let size = get_size(&path, opts.use_disk_usage, opts.excludes, opts.pool).unwrap_or(0);
+	let mtime = if opts.sort_key == SortKey::Mtime {
+	    entry.metadata().and_then(|m| m.modified()).ok()
+	} else {
+	    None
+	};
+	entries.push((path, is_dir, size, mtime));
+    }
+    match opts.sort_key {
+	SortKey::Name => entries.sort_by(|a, b| a.0.file_name().cmp(&b.0.file_name())),
+	// Largest/newest first, so the biggest consumers surface at the top of each level.
+	SortKey::Size => entries.sort_by_key(|e| std::cmp::Reverse(e.2)),
+	SortKey::Mtime => entries.sort_by_key(|e| std::cmp::Reverse(e.3)),
     }
-    for f in files {
-	println!("{spacing}\x1b[39m{}\x1b[0m", f.display());
+    if opts.reverse {
+	entries.reverse();
+    }
+
+    let max_size = entries.iter()
+	.filter(|(_, is_dir, _, _)| entry_passes_filter(opts.filter, *is_dir))
+	.map(|(_, _, size, _)| *size)
+	.max()
+	.unwrap_or(0);
+    // Remaining columns after the prefix, the 4-column connector, the name
+    // and size columns, the two separating spaces and the bar's brackets.
+    let bar_width = opts.term_width
+	.saturating_sub(prefix.chars().count())
+	.saturating_sub(4 + NAME_COLUMN_WIDTH + SIZE_COLUMN_WIDTH + 2 + 2);
+
+    // An entry counts toward tree position (is_last, connectors) if it prints
+    // its own line, or — being hidden by the filter itself — still has a
+    // descendant that will. Otherwise it's invisible to the rendered tree.
+    let produces_output: Vec<bool> = entries.iter().map(|(path, is_dir, _, _)| {
+	if entry_passes_filter(opts.filter, *is_dir) {
+	    true
+	} else if *is_dir && depth < max_depth {
+	    subtree_has_visible_entry(path, depth + 1, max_depth, opts)
+	} else {
+	    false
+	}
+    }).collect();
+    let last_visible_index = produces_output.iter()
+	.enumerate()
+	.rev()
+	.find(|(_, visible)| **visible)
+	.map(|(i, _)| i);
+
+    for (i, (path, is_dir, size, _)) in entries.iter().enumerate() {
+	let shown = entry_passes_filter(opts.filter, *is_dir);
+	let is_last = Some(i) == last_visible_index;
+	if shown {
+	    let connector = if is_last { "\u{2514}\u{2500}\u{2500} " } else { "\u{251c}\u{2500}\u{2500} " };
+	    let name = path.file_name()
+		.map(|n| n.to_string_lossy().to_string())
+		.unwrap_or_else(|| path.display().to_string());
+	    let (color, reset) = if opts.ascii {
+		("", "")
+	    } else if *is_dir {
+		("\x1b[36m", "\x1b[0m")
+	    } else {
+		("\x1b[39m", "\x1b[0m")
+	    };
+	    let size_str = pretty_format_bytes(*size, opts.unit_mode);
+	    let fraction = if max_size == 0 { 0.0 } else { *size as f64 / max_size as f64 };
+	    let bar = render_bar(fraction, bar_width, opts.ascii);
+	    println!("{prefix}{connector}{color}{name:<NAME_COLUMN_WIDTH$}{reset} {size_str:>SIZE_COLUMN_WIDTH$} {bar}");
+	}
+
+	if *is_dir && depth < max_depth {
+	    let child_prefix = if shown {
+		format!("{prefix}{}", if is_last { "    " } else { "\u{2502}   " })
+	    } else {
+		// This entry never got its own line, so its children shouldn't
+		// be indented under a connector that was never drawn.
+		prefix.to_string()
+	    };
+	    display_dir(path, &child_prefix, depth + 1, max_depth, opts)?;
+	}
     }
     Ok(())
 }
@@ -119,8 +419,19 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
     let mut show_dots = false;
     let mut only_info = false;
     let mut appd_info = false;
+    let mut max_depth: u32 = 1;
+    let mut use_disk_usage = false;
+    let mut excludes: Vec<Regex> = Vec::new();
+    let mut unit_mode = UnitMode::Decimal;
+    let mut workers: usize = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let mut ascii = false;
+    let mut sort_key = SortKey::Size;
+    let mut reverse = false;
+    let mut filter = EntryFilter::All;
     let mut directories = Vec::new();
-    for arg in args.iter() {
+
+    let mut iter = args.into_iter().peekable();
+    while let Some(arg) = iter.next() {
 	if arg == "--help" {
 	    usage(program);
 	    return Ok(true);
@@ -135,6 +446,47 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
 	} else if arg == "-I" {
 	    appd_info = true;
 	    only_info = false;
+	} else if arg == "-u" || arg == "--usage" {
+	    use_disk_usage = true;
+	    continue;
+	} else if arg == "-d" || arg == "--depth" {
+	    let value = iter.next().ok_or_else(|| format!("Expected a number after `{arg}`"))?;
+	    max_depth = value.parse::<u32>().map_err(|_| format!("Expected a number after `{arg}`, got `{value}`"))?;
+	} else if let Some(value) = arg.strip_prefix("--depth=") {
+	    max_depth = value.parse::<u32>().map_err(|_| format!("Expected a number for `--depth`, got `{value}`"))?;
+	} else if arg == "-x" || arg == "--exclude" {
+	    let pattern = iter.next().ok_or_else(|| format!("Expected a pattern after `{arg}`"))?;
+	    excludes.push(glob_to_regex(&pattern)?);
+	} else if let Some(pattern) = arg.strip_prefix("--exclude=") {
+	    excludes.push(glob_to_regex(pattern)?);
+	} else if arg == "-b" || arg == "--bytes" {
+	    unit_mode = UnitMode::Bytes;
+	    continue;
+	} else if arg == "--iec" {
+	    unit_mode = UnitMode::Iec;
+	} else if arg == "-j" || arg == "--jobs" {
+	    let value = iter.next().ok_or_else(|| format!("Expected a number after `{arg}`"))?;
+	    workers = value.parse::<usize>().map_err(|_| format!("Expected a number after `{arg}`, got `{value}`"))?.max(1);
+	} else if let Some(value) = arg.strip_prefix("--jobs=") {
+	    workers = value.parse::<usize>().map_err(|_| format!("Expected a number for `--jobs`, got `{value}`"))?.max(1);
+	} else if arg == "-A" || arg == "--ascii" {
+	    ascii = true;
+	    continue;
+	} else if arg == "--reverse" {
+	    reverse = true;
+	} else if arg == "--dirs-only" {
+	    filter = EntryFilter::DirsOnly;
+	} else if arg == "--files-only" {
+	    filter = EntryFilter::FilesOnly;
+	} else if let Some(value) = arg.strip_prefix("--sort=") {
+	    sort_key = match value {
+		"name" => SortKey::Name,
+		"size" => SortKey::Size,
+		"mtime" => SortKey::Mtime,
+		_ => return Err(format!("Unknown value for `--sort`: `{value}` (expected name, size, or mtime)")),
+	    };
+	} else if arg.starts_with("--") {
+	    return Err(format!("Unknown flag used. Don't recognize flag `{arg}`"));
 	} else if arg.starts_with("-") {
 	    let mut chars = arg.chars().skip(1);
 	    while let Some(ch) = chars.next() {
@@ -151,6 +503,15 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
 			appd_info = true;
 			only_info = false;
 		    },
+		    'u' => {
+			use_disk_usage = true;
+		    },
+		    'b' => {
+			unit_mode = UnitMode::Bytes;
+		    },
+		    'A' => {
+			ascii = true;
+		    },
 		    _ => return Err(format!("Unknown flag used. Don't recognize flag `{ch}` from `{arg}`")),
 		};
 	    }
@@ -165,6 +526,24 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
     let directories = directories;
     let show_dots = show_dots;
     let only_info = only_info;
+    let max_depth = max_depth;
+    let pool = rayon::ThreadPoolBuilder::new()
+	.num_threads(workers)
+	.build()
+	.map_err(|e| format!("Failed to set up the worker thread pool: {e}"))?;
+    let term_width = terminal_width();
+    let opts = ListOptions {
+	show_dots,
+	use_disk_usage,
+	excludes: &excludes,
+	unit_mode,
+	pool: &pool,
+	term_width,
+	ascii,
+	sort_key,
+	reverse,
+	filter,
+    };
     if directories.len() == 1 {
 	let path = &directories[0];
 	if !path.exists() {
@@ -173,18 +552,18 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
 
 	return if !path.is_dir() || only_info {
 	    print!("{}:", path.display());
-	    match display_info(path, "") {
+	    match display_info(path, "", use_disk_usage, &excludes, unit_mode, &pool) {
 		Ok(_) => Ok(true),
 		Err(e) => Err(format!("Failed to get metadata: {e}")),
 	    }
 	} else {
 	    if appd_info {
-		match display_info(path, "") {
+		match display_info(path, "", use_disk_usage, &excludes, unit_mode, &pool) {
 		    Ok(_) => {},
 		    Err(e) => eprintln!("\x1b[31;1mERROR\x1b[0m> Failed to get metadata: {e}"),
 		};
 	    }
-	    match display_dir(path, show_dots, "") {
+	    match display_dir(path, "", 1, max_depth, &opts) {
 		Ok(_) => Ok(true),
 		Err(e) => Err(format!("Problem happened while attempting to read directory: {e}"))
 	    }
@@ -200,7 +579,7 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
 	}
 	let spacing = "  ";
 	if !path.is_dir() || only_info {
-	    match display_info(path, spacing) {
+	    match display_info(path, spacing, use_disk_usage, &excludes, unit_mode, &pool) {
 		Ok(_) => { succeeded += 1; },
 		Err(e) => eprintln!("\x1b[31;1mERROR\x1b[0m> Failed to get metadata: {e}")
 	    };
@@ -208,18 +587,18 @@ fn run(program: &String, args: Vec<String>) -> Result<bool, String> {
 	}
 
 	if appd_info {
-	    match display_info(path, "") {
+	    match display_info(path, "", use_disk_usage, &excludes, unit_mode, &pool) {
 		Ok(_) => {},
 		Err(e) => eprintln!("\x1b[31;1mERROR\x1b[0m> Failed to get metadata: {e}"),
 	    };
 	}
-	
-	match display_dir(path, show_dots, spacing) {
+
+	match display_dir(path, spacing, 1, max_depth, &opts) {
 	    Ok(_) => { succeeded += 1; },
 	    Err(e) => eprintln!("\x1b[31;1mERROR\x1b[0m> Problem happened while attempting to read directory: {e}")
 	};
     }
-    
+
     return Ok(succeeded > 0);
 }
 